@@ -17,8 +17,12 @@ pub enum ResolveError {
     },
     #[error("unsupported relation kind: {0}")]
     UnsupportedRelationKind(char),
-    #[error("unsupported PostgreSQL type oid {type_oid} for column {column}")]
-    UnsupportedType { column: String, type_oid: u32 },
+    #[error("unsupported PostgreSQL type {type_name} (oid {type_oid}) for column {column}")]
+    UnsupportedType {
+        column: String,
+        type_name: String,
+        type_oid: u32,
+    },
     #[error("{kind} identifier `{identifier}` exceeds PostgreSQL limit of {max_bytes} bytes")]
     OverlongIdentifier {
         kind: &'static str,
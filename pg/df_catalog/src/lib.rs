@@ -59,7 +59,7 @@
 
 mod error;
 
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::panic::AssertUnwindSafe;
 use std::sync::Arc;
 
@@ -226,6 +226,7 @@ fn resolve_relation_by_oid(
             let data_type =
                 oid_to_arrow_type(attr.atttypid).ok_or_else(|| ResolveError::UnsupportedType {
                     column: attr.name().to_owned(),
+                    type_name: type_display_name(attr.atttypid),
                     type_oid: attr.atttypid.to_u32(),
                 })?;
             fields.push(Field::new(attr.name(), data_type, !attr.attnotnull));
@@ -296,6 +297,18 @@ fn validate_relkind(rel: &PgRelation) -> Result<(), ResolveError> {
     Err(ResolveError::UnsupportedRelationKind(relkind))
 }
 
+/// Render a type OID the way PostgreSQL error messages do, e.g. `jsonb` or
+/// `time with time zone`. `format_type_be` raises an ERROR rather than
+/// returning NULL; callers run inside `PgTryBuilder`, which catches it.
+fn type_display_name(type_oid: pg_sys::Oid) -> String {
+    unsafe {
+        let name = pg_sys::format_type_be(type_oid);
+        let display = CStr::from_ptr(name).to_string_lossy().into_owned();
+        pg_sys::pfree(name.cast());
+        display
+    }
+}
+
 fn oid_to_arrow_type(oid: pg_sys::Oid) -> Option<arrow_schema::DataType> {
     match oid {
         o if o == pg_sys::BOOLOID => Some(DataType::Boolean),
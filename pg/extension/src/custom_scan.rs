@@ -817,7 +817,7 @@ fn handle_primary_control(
         } => {
             host_diag(DiagnosticLogLevel::Basic, || {
                 format!(
-                    "pg_fusion backend received FailExecution session_epoch={} code={:?} detail={:?} state={}",
+                    "pg_fusion backend received FailExecution session_epoch={} code={} detail={:?} state={}",
                     session_epoch,
                     code,
                     detail,
@@ -829,7 +829,7 @@ fn handle_primary_control(
             state.active_drivers.clear();
             state.pending_complete_session_epoch = None;
             state.terminal_error = Some(format!(
-                "worker failed execution session_epoch={session_epoch} code={code:?} detail={detail:?}"
+                "worker failed execution session_epoch={session_epoch} code={code} detail={detail:?}"
             ));
             host_diag(DiagnosticLogLevel::Basic, || {
                 format!(
@@ -1356,7 +1356,7 @@ fn decode_primary_inbound(bytes: &[u8]) -> Result<PrimaryInbound, Box<dyn std::e
         Ok(RuntimeMessageFamily::WorkerExecutionToBackend) => Ok(PrimaryInbound::Control(
             decode_worker_execution_to_backend(bytes)?,
         )),
-        Ok(other) => Err(format!("unexpected primary message family {other:?}").into()),
+        Ok(other) => Err(format!("unexpected primary message family {other}").into()),
         Err(runtime_error)
             if matches!(
                 runtime_error,
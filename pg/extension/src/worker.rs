@@ -366,7 +366,7 @@ fn handle_steps(
                 warn!(
                     component = "worker",
                     session_epoch,
-                    code = ?code,
+                    code = %code,
                     detail = ?detail,
                     "worker observed execution failure transition"
                 );
@@ -82,9 +82,12 @@ pub(crate) fn render_literal(literal: &ScalarValue) -> Option<String> {
         }
         ScalarValue::TimestampNanosecond(_, _) => None,
         ScalarValue::Dictionary(_, value) => render_literal(value),
+        ScalarValue::IntervalMonthDayNano(value) => value.map_or_else(
+            || Some("NULL".into()),
+            |value| render_interval_literal(value.months, value.days, value.nanoseconds),
+        ),
         ScalarValue::IntervalYearMonth(_)
         | ScalarValue::IntervalDayTime(_)
-        | ScalarValue::IntervalMonthDayNano(_)
         | ScalarValue::DurationSecond(_)
         | ScalarValue::DurationMillisecond(_)
         | ScalarValue::DurationMicrosecond(_)
@@ -169,7 +172,7 @@ fn render_option_number<T: ToString>(value: Option<T>) -> Option<String> {
 }
 
 fn render_bytea_literal(bytes: &[u8]) -> String {
-    format!("'\\\\x{}'::bytea", encode_hex(bytes))
+    format!("'\\x{}'::bytea", encode_hex(bytes))
 }
 
 fn render_date32_literal(days: i32) -> String {
@@ -186,6 +189,18 @@ fn render_time_literal(value: i64, unit: &str) -> String {
     format!("((TIME '00:00:00' + ({value}) * INTERVAL '1 {unit}')::time)")
 }
 
+/// PostgreSQL intervals keep microseconds, so sub-microsecond values stay
+/// residual. Every field carries an explicit sign because `sql_standard`
+/// `IntervalStyle` otherwise applies a leading sign to all fields.
+fn render_interval_literal(months: i32, days: i32, nanoseconds: i64) -> Option<String> {
+    (nanoseconds % 1_000 == 0).then(|| {
+        format!(
+            "INTERVAL '{months:+} mons {days:+} days {:+} microseconds'",
+            nanoseconds / 1_000
+        )
+    })
+}
+
 fn render_timestamp_literal(
     value: Option<i64>,
     timezone: Option<&str>,
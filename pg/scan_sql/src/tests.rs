@@ -1,6 +1,7 @@
 use super::*;
-use arrow_schema::{DataType, Field, Schema, TimeUnit};
-use datafusion_common::{Column, TableReference};
+use arrow_schema::{DataType, Field, IntervalUnit, Schema, TimeUnit};
+use datafusion_common::arrow::datatypes::IntervalMonthDayNano;
+use datafusion_common::{Column, ScalarValue, TableReference};
 use datafusion_expr::expr::{BinaryExpr, Cast, InList, Like};
 use datafusion_expr::{lit, Expr, Operator};
 
@@ -633,3 +634,140 @@ fn compiles_int8_cast_using_postgresql_smallint_target() {
         "SELECT \"name\" FROM \"public\".\"users\" WHERE (CAST(\"id\" AS SMALLINT) > 5)"
     );
 }
+
+fn interval_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new(
+            "elapsed",
+            DataType::Interval(IntervalUnit::MonthDayNano),
+            true,
+        ),
+    ])
+}
+
+fn interval_literal(months: i32, days: i32, nanoseconds: i64) -> Expr {
+    lit(ScalarValue::IntervalMonthDayNano(Some(
+        IntervalMonthDayNano::new(months, days, nanoseconds),
+    )))
+}
+
+#[test]
+fn renders_month_day_nano_interval_literal_with_signed_fields() {
+    let schema = interval_schema();
+    let filter = Expr::Column(Column::from_name("elapsed")).gt(interval_literal(
+        -1,
+        15,
+        -14_706_000_000_000,
+    ));
+
+    let compiled = compile_scan(CompileScanInput {
+        relation: &test_relation(),
+        schema: &schema,
+        identifier_max_bytes: TEST_IDENTIFIER_MAX_BYTES,
+        projection: Some(&[0]),
+        filters: std::slice::from_ref(&filter),
+        requested_limit: None,
+        limit_lowering: LimitLowering::ExternalHint,
+    })
+    .unwrap();
+
+    assert!(compiled.all_filters_compiled);
+    assert_eq!(
+        compiled.sql,
+        "SELECT \"id\" FROM \"public\".\"users\" WHERE (\"elapsed\" > INTERVAL '-1 mons +15 days -14706000000 microseconds')"
+    );
+}
+
+#[test]
+fn renders_null_and_extreme_month_day_nano_interval_literals() {
+    let schema = interval_schema();
+    let cases = [
+        (ScalarValue::IntervalMonthDayNano(None), "NULL".to_string()),
+        (
+            ScalarValue::IntervalMonthDayNano(Some(IntervalMonthDayNano::new(
+                i32::MIN,
+                0,
+                i64::MIN + 808,
+            ))),
+            format!(
+                "INTERVAL '{} mons +0 days -9223372036854775 microseconds'",
+                i32::MIN
+            ),
+        ),
+    ];
+
+    for (value, rendered) in cases {
+        let filter = Expr::Column(Column::from_name("elapsed")).eq(lit(value));
+        let compiled = compile_scan(CompileScanInput {
+            relation: &test_relation(),
+            schema: &schema,
+            identifier_max_bytes: TEST_IDENTIFIER_MAX_BYTES,
+            projection: Some(&[0]),
+            filters: std::slice::from_ref(&filter),
+            requested_limit: None,
+            limit_lowering: LimitLowering::ExternalHint,
+        })
+        .unwrap();
+
+        assert!(compiled.all_filters_compiled);
+        assert_eq!(
+            compiled.sql,
+            format!("SELECT \"id\" FROM \"public\".\"users\" WHERE (\"elapsed\" = {rendered})")
+        );
+    }
+}
+
+#[test]
+fn leaves_sub_microsecond_interval_literals_residual() {
+    let schema = interval_schema();
+    let filter = Expr::Column(Column::from_name("elapsed")).lt(interval_literal(0, 1, 1_500));
+
+    let compiled = compile_scan(CompileScanInput {
+        relation: &test_relation(),
+        schema: &schema,
+        identifier_max_bytes: TEST_IDENTIFIER_MAX_BYTES,
+        projection: Some(&[0]),
+        filters: std::slice::from_ref(&filter),
+        requested_limit: None,
+        limit_lowering: LimitLowering::ExternalHint,
+    })
+    .unwrap();
+
+    assert!(!compiled.all_filters_compiled);
+    assert_eq!(compiled.residual_filters, vec![filter]);
+    assert_eq!(compiled.residual_filter_columns, vec![1]);
+}
+
+#[test]
+fn renders_bytea_literals_with_single_backslash_hex_escape() {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("payload", DataType::Binary, true),
+    ]);
+    let cases = [
+        (Some(vec![]), "'\\x'::bytea"),
+        (Some(vec![0xde, 0xad, 0xbe, 0xef]), "'\\xDEADBEEF'::bytea"),
+        (None, "NULL"),
+    ];
+
+    for (value, rendered) in cases {
+        let filter = Expr::Column(Column::from_name("payload")).eq(lit(ScalarValue::Binary(value)));
+        let compiled = compile_scan(CompileScanInput {
+            relation: &test_relation(),
+            schema: &schema,
+            identifier_max_bytes: TEST_IDENTIFIER_MAX_BYTES,
+            projection: Some(&[0]),
+            filters: std::slice::from_ref(&filter),
+            requested_limit: None,
+            limit_lowering: LimitLowering::ExternalHint,
+        })
+        .unwrap();
+
+        assert!(compiled.all_filters_compiled);
+        assert_eq!(
+            compiled.sql,
+            format!("SELECT \"id\" FROM \"public\".\"users\" WHERE (\"payload\" = {rendered})")
+        );
+    }
+}
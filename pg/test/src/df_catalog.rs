@@ -415,8 +415,9 @@ pub fn df_catalog_rejects_unsupported_types() {
         err,
         ResolveError::UnsupportedType {
             column,
+            type_name,
             type_oid: _
-        } if column == "payload"
+        } if column == "payload" && type_name == "jsonb"
     ));
 }
 
@@ -431,8 +432,11 @@ pub fn df_catalog_rejects_timetz_columns() {
         err,
         ResolveError::UnsupportedType {
             column,
+            type_name,
             type_oid
-        } if column == "at_local" && type_oid == pg_sys::TIMETZOID.to_u32()
+        } if column == "at_local"
+            && type_name == "time with time zone"
+            && type_oid == pg_sys::TIMETZOID.to_u32()
     ));
 }
 
@@ -8,6 +8,8 @@
 //! - worker-to-backend execution control is carried only on the primary slot
 //! - worker-to-backend scan control is carried only on dedicated scan slots
 
+use std::fmt;
+
 use crate::error::DecodeError;
 use crate::scan::{
     PlanFlowDescriptor, ScanChannelSet, ScanChannelSetRef, ScanFlowDescriptor,
@@ -42,6 +44,17 @@ impl TryFrom<u8> for RuntimeMessageFamily {
     }
 }
 
+impl fmt::Display for RuntimeMessageFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::BackendExecutionToWorker => "backend_execution_to_worker",
+            Self::WorkerExecutionToBackend => "worker_execution_to_backend",
+            Self::WorkerScanToBackend => "worker_scan_to_backend",
+            Self::BackendScanToWorker => "backend_scan_to_worker",
+        })
+    }
+}
+
 /// Versioned failure codes for runtime control-plane failures.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u8)]
@@ -70,6 +83,17 @@ impl TryFrom<u8> for ExecutionFailureCode {
     }
 }
 
+impl fmt::Display for ExecutionFailureCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Cancelled => "cancelled",
+            Self::ProtocolViolation => "protocol_violation",
+            Self::TransportRestarted => "transport_restarted",
+            Self::Internal => "internal",
+        })
+    }
+}
+
 /// Query-scoped worker execution options captured by the backend at start.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ExecutionOptionsWire {
@@ -675,6 +675,46 @@ fn encoded_len_matches_written_worker_scan_message() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn encode_into_undersized_buffer_leaves_it_untouched() {
+    let channels = scan_channels();
+    let message = BackendExecutionToWorker::StartExecution {
+        session_epoch: 5,
+        plan: plan_descriptor(),
+        options: ExecutionOptionsWire::default(),
+        scans: ScanChannelSet::new(&channels).expect("valid scan set"),
+    };
+    let expected = encoded_len_backend_execution_to_worker(message);
+    let mut buf = vec![0xa5u8; expected - 1];
+    let err = encode_backend_execution_to_worker_into(message, &mut buf).expect_err("too small");
+    assert_eq!(
+        err,
+        EncodeError::BufferTooSmall {
+            expected,
+            actual: expected - 1,
+        }
+    );
+    assert!(buf.iter().all(|&byte| byte == 0xa5));
+
+    let message = BackendScanToWorker::ScanFailed {
+        session_epoch: 5,
+        scan_id: 17,
+        producer_id: 2,
+        message: "scan failed",
+    };
+    let expected = encoded_len_backend_scan_to_worker(message);
+    let mut buf = vec![0xa5u8; RUNTIME_ENVELOPE_HEADER_LEN];
+    let err = encode_backend_scan_to_worker_into(message, &mut buf).expect_err("too small");
+    assert_eq!(
+        err,
+        EncodeError::BufferTooSmall {
+            expected,
+            actual: RUNTIME_ENVELOPE_HEADER_LEN,
+        }
+    );
+    assert!(buf.iter().all(|&byte| byte == 0xa5));
+}
+
 #[test]
 fn plan_descriptor_reconstructs_plan_open() {
     let session_epoch = 14;
@@ -761,6 +801,33 @@ fn decode_backend_scan_failed_rejects_trailing_bytes() {
     assert!(matches!(err, DecodeError::TrailingBytes { remaining: 1 }));
 }
 
+#[test]
+fn decode_backend_scan_failed_rejects_inflated_message_len() {
+    let mut fixstr = encode_raw_backend_scan_failed(2, 3, 0, "boom");
+    let marker = fixstr.len() - "boom".len() - 1;
+    assert_eq!(fixstr[marker], 0xa4);
+    fixstr[marker] = 0xbf;
+    let err = decode_backend_scan_to_worker(&fixstr).expect_err("inflated fixstr len");
+    assert_eq!(
+        err,
+        DecodeError::MsgPack("truncated string payload: expected 31 bytes, got 4".into())
+    );
+
+    let mut str32 = encode_raw_backend_scan_failed(2, 3, 0, "");
+    assert_eq!(str32.pop(), Some(0xa0));
+    str32.push(0xdb);
+    str32.extend_from_slice(&u32::MAX.to_be_bytes());
+    str32.extend_from_slice(b"boom");
+    let err = decode_backend_scan_to_worker(&str32).expect_err("inflated str32 len");
+    assert_eq!(
+        err,
+        DecodeError::MsgPack(format!(
+            "truncated string payload: expected {} bytes, got 4",
+            u32::MAX
+        ))
+    );
+}
+
 #[test]
 fn decode_rejects_empty_producer_set() {
     let encoded = encode_raw_open_scan(2, 3, 0x0101, 0, &encode_raw_producer_set(&[]));
@@ -807,6 +874,47 @@ fn decode_rejects_invalid_producer_role() {
     assert_eq!(err, DecodeError::InvalidProducerRole { actual: 9 });
 }
 
+#[test]
+fn protocol_enums_display_stable_names() {
+    let families = [
+        (
+            RuntimeMessageFamily::BackendExecutionToWorker,
+            "backend_execution_to_worker",
+        ),
+        (
+            RuntimeMessageFamily::WorkerExecutionToBackend,
+            "worker_execution_to_backend",
+        ),
+        (
+            RuntimeMessageFamily::WorkerScanToBackend,
+            "worker_scan_to_backend",
+        ),
+        (
+            RuntimeMessageFamily::BackendScanToWorker,
+            "backend_scan_to_worker",
+        ),
+    ];
+    for (family, name) in families {
+        assert_eq!(family.to_string(), name);
+    }
+
+    let codes = [
+        (ExecutionFailureCode::Cancelled, "cancelled"),
+        (
+            ExecutionFailureCode::ProtocolViolation,
+            "protocol_violation",
+        ),
+        (
+            ExecutionFailureCode::TransportRestarted,
+            "transport_restarted",
+        ),
+        (ExecutionFailureCode::Internal, "internal"),
+    ];
+    for (code, name) in codes {
+        assert_eq!(code.to_string(), name);
+    }
+}
+
 #[test]
 fn decode_preserves_nonminimal_array16_producer_header() {
     let producer_bytes = encode_raw_producer_set_array16(&[
@@ -892,3 +1000,455 @@ fn max_message_len_matches_control_transport_rule() {
     assert_eq!(max_message_len_for_ring_capacity(5), 0);
     assert_eq!(max_message_len_for_ring_capacity(64), 59);
 }
+
+struct FuzzRng(u64);
+
+impl FuzzRng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut value = self.0;
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        value ^ (value >> 31)
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    fn fill(&mut self, bytes: &mut [u8]) {
+        for byte in bytes {
+            *byte = self.next_u64() as u8;
+        }
+    }
+}
+
+/// Run every decode entrypoint over `bytes` and walk any borrowed sets they
+/// return. Returns whether any full-message decoder accepted the frame.
+fn decode_with_every_entrypoint(bytes: &[u8]) -> bool {
+    let _ = decode_runtime_message_family(bytes);
+    let mut accepted = false;
+
+    if let Ok(message) = decode_backend_execution_to_worker(bytes) {
+        if let BackendExecutionToWorkerRef::StartExecution { scans, .. } = message {
+            assert_eq!(scans.iter().count(), scans.len());
+        }
+        accepted = true;
+    }
+    accepted |= decode_worker_execution_to_backend(bytes).is_ok();
+    if let Ok(message) = decode_worker_scan_to_backend(bytes) {
+        if let WorkerScanToBackendRef::OpenScan { scan, .. } = message {
+            assert_eq!(scan.producers().iter().count(), scan.producers().len());
+        }
+        accepted = true;
+    }
+    accepted |= decode_backend_scan_to_worker(bytes).is_ok();
+    accepted
+}
+
+fn runtime_header(family: RuntimeMessageFamily, tag: u8) -> Vec<u8> {
+    let mut header = Vec::with_capacity(RUNTIME_ENVELOPE_HEADER_LEN);
+    write_runtime_header_to(&mut header, family, tag).expect("header");
+    header
+}
+
+#[test]
+fn decode_rejects_random_bytes_without_panicking() {
+    let mut rng = FuzzRng(0x7066_5f66_757a_7a31);
+    for _ in 0..4096 {
+        let mut bytes = vec![0u8; rng.below(96)];
+        rng.fill(&mut bytes);
+        assert!(
+            !decode_with_every_entrypoint(&bytes),
+            "random frame unexpectedly decoded: {bytes:02x?}"
+        );
+    }
+}
+
+#[test]
+fn decode_survives_random_payloads_behind_valid_envelope() {
+    const FAMILIES: [RuntimeMessageFamily; 4] = [
+        RuntimeMessageFamily::BackendExecutionToWorker,
+        RuntimeMessageFamily::WorkerExecutionToBackend,
+        RuntimeMessageFamily::WorkerScanToBackend,
+        RuntimeMessageFamily::BackendScanToWorker,
+    ];
+
+    let mut rng = FuzzRng(0x7066_5f66_757a_7a32);
+    for _ in 0..4096 {
+        let family = FAMILIES[rng.below(FAMILIES.len())];
+        let tag = rng.below(4) as u8;
+        let mut bytes = runtime_header(family, tag);
+        let mut payload = vec![0u8; rng.below(128)];
+        rng.fill(&mut payload);
+        bytes.extend_from_slice(&payload);
+        let _ = decode_with_every_entrypoint(&bytes);
+    }
+}
+
+#[test]
+fn decode_survives_mutated_and_truncated_valid_frames() {
+    let channels = scan_channels();
+    let producers = producer_descriptors();
+    let frames = [
+        encode_backend(BackendExecutionToWorker::StartExecution {
+            session_epoch: 9,
+            plan: plan_descriptor(),
+            options: ExecutionOptionsWire::default(),
+            scans: ScanChannelSet::new(&channels).expect("scan channels"),
+        }),
+        encode_backend(BackendExecutionToWorker::FailExecution {
+            session_epoch: 9,
+            code: ExecutionFailureCode::Internal,
+            detail: Some(17),
+        }),
+        encode_worker_execution(WorkerExecutionToBackend::CompleteExecution { session_epoch: 9 }),
+        encode_worker_scan(WorkerScanToBackend::OpenScan {
+            session_epoch: 9,
+            scan_id: 3,
+            scan: ScanFlowDescriptor::new(0x0202, 1, &producers).expect("producers"),
+        }),
+        encode_backend_scan(BackendScanToWorker::ScanFailed {
+            session_epoch: 9,
+            scan_id: 3,
+            producer_id: 1,
+            message: "scan failed",
+        }),
+    ];
+
+    let mut rng = FuzzRng(0x7066_5f66_757a_7a33);
+    for frame in &frames {
+        for len in 0..frame.len() {
+            assert!(
+                !decode_with_every_entrypoint(&frame[..len]),
+                "truncated frame unexpectedly decoded at len {len}"
+            );
+        }
+        for _ in 0..1024 {
+            let mut mutated = frame.clone();
+            for _ in 0..=rng.below(4) {
+                let index = rng.below(mutated.len());
+                mutated[index] = rng.next_u64() as u8;
+            }
+            let _ = decode_with_every_entrypoint(&mutated);
+        }
+    }
+}
+
+#[test]
+fn decode_runtime_family_checks_every_family_and_tag_byte() {
+    const FAMILY_OFFSET: usize = RUNTIME_ENVELOPE_HEADER_LEN - 2;
+    const TAG_OFFSET: usize = RUNTIME_ENVELOPE_HEADER_LEN - 1;
+
+    let valid = runtime_header(RuntimeMessageFamily::BackendExecutionToWorker, 0);
+    for family in 0..=u8::MAX {
+        for tag in 0..=u8::MAX {
+            let mut header = valid.clone();
+            header[FAMILY_OFFSET] = family;
+            header[TAG_OFFSET] = tag;
+            let decoded = decode_runtime_message_family(&header);
+            match RuntimeMessageFamily::try_from(family) {
+                Ok(expected) => {
+                    assert!((1..=4).contains(&family));
+                    assert_eq!(expected as u8, family);
+                    assert_eq!(decoded, Ok(expected));
+                }
+                Err(_) => assert_eq!(
+                    decoded,
+                    Err(DecodeError::UnexpectedMessageFamily { actual: family })
+                ),
+            }
+        }
+    }
+}
+
+#[test]
+fn decode_runtime_family_rejects_every_header_bit_flip_and_truncation() {
+    let valid = runtime_header(RuntimeMessageFamily::WorkerScanToBackend, 0);
+    let magic = u32::from_be_bytes([valid[0], valid[1], valid[2], valid[3]]);
+    let version = u16::from_be_bytes([valid[4], valid[5]]);
+
+    for bit in 0..u32::BITS {
+        let actual = magic ^ (1 << bit);
+        let mut header = valid.clone();
+        header[..4].copy_from_slice(&actual.to_be_bytes());
+        assert_eq!(
+            decode_runtime_message_family(&header),
+            Err(DecodeError::InvalidMagic {
+                expected: magic,
+                actual,
+            })
+        );
+    }
+    for bit in 0..u16::BITS {
+        let actual = version ^ (1 << bit);
+        let mut header = valid.clone();
+        header[4..6].copy_from_slice(&actual.to_be_bytes());
+        assert_eq!(
+            decode_runtime_message_family(&header),
+            Err(DecodeError::UnsupportedVersion {
+                expected: version,
+                actual,
+            })
+        );
+    }
+    for len in 0..RUNTIME_ENVELOPE_HEADER_LEN {
+        assert_eq!(
+            decode_runtime_message_family(&valid[..len]),
+            Err(DecodeError::TruncatedEnvelope {
+                expected: RUNTIME_ENVELOPE_HEADER_LEN,
+                actual: len,
+            })
+        );
+    }
+}
+
+const ROUND_TRIP_CASES: usize = 1024;
+
+fn random_failure(rng: &mut FuzzRng) -> (ExecutionFailureCode, Option<u64>) {
+    const CODES: [ExecutionFailureCode; 4] = [
+        ExecutionFailureCode::Cancelled,
+        ExecutionFailureCode::ProtocolViolation,
+        ExecutionFailureCode::TransportRestarted,
+        ExecutionFailureCode::Internal,
+    ];
+    let code = CODES[rng.below(CODES.len())];
+    let detail = (rng.below(2) == 0).then(|| rng.next_u64());
+    (code, detail)
+}
+
+/// Build a valid scan-channel map: sorted by `(scan_id, producer_id)` with
+/// exactly one leader per scan.
+fn random_scan_channels(rng: &mut FuzzRng) -> Vec<ScanChannelDescriptorWire> {
+    let mut channels = Vec::new();
+    let mut scan_id = rng.next_u64() >> 8;
+    for _ in 0..rng.below(5) {
+        scan_id += 1 + rng.below(1 << 16) as u64;
+        let producers = 1 + rng.below(4);
+        let leader = rng.below(producers);
+        let mut producer_id = rng.below(64) as u16;
+        for index in 0..producers {
+            channels.push(ScanChannelDescriptorWire {
+                scan_id,
+                producer_id,
+                role: if index == leader {
+                    ProducerRole::Leader
+                } else {
+                    ProducerRole::Worker
+                },
+                peer: backend_peer(rng.next_u64() as u32, rng.next_u64(), rng.next_u64()),
+            });
+            producer_id += 1 + rng.below(64) as u16;
+        }
+    }
+    channels
+}
+
+/// Build a valid producer set: distinct ids in random order with at most one
+/// leader.
+fn random_producers(rng: &mut FuzzRng) -> Vec<ProducerDescriptorWire> {
+    let count = 1 + rng.below(16);
+    let leader = rng.below(count + 1);
+    let mut producers: Vec<_> = (0..count)
+        .map(|index| ProducerDescriptorWire {
+            producer_id: 0,
+            role: if index == leader {
+                ProducerRole::Leader
+            } else {
+                ProducerRole::Worker
+            },
+        })
+        .collect();
+    let mut producer_id = rng.next_u64() as u16 / 2;
+    for index in 0..count {
+        producer_id = producer_id.wrapping_add(1 + rng.below(1024) as u16);
+        producers[index].producer_id = producer_id;
+        producers.swap(index, rng.below(index + 1));
+    }
+    producers
+}
+
+/// Build a UTF-8 message up to the bounded scan-failure length, mixing
+/// multi-byte characters so the byte bound is exercised off char boundaries.
+fn random_failure_message(rng: &mut FuzzRng) -> String {
+    const CHARS: [char; 6] = ['a', 'Z', ' ', 'é', '€', '𝄞'];
+    let target = rng.below(MAX_SCAN_FAILURE_MESSAGE_LEN + 1);
+    let mut message = String::new();
+    loop {
+        let ch = CHARS[rng.below(CHARS.len())];
+        if message.len() + ch.len_utf8() > target {
+            return message;
+        }
+        message.push(ch);
+    }
+}
+
+#[test]
+fn backend_execution_round_trips_random_messages() {
+    let mut rng = FuzzRng(0x7066_5f72_7472_7031);
+    for _ in 0..ROUND_TRIP_CASES {
+        let session_epoch = rng.next_u64();
+        match rng.below(3) {
+            0 => {
+                let channels = random_scan_channels(&mut rng);
+                let plan = PlanFlowDescriptor {
+                    plan_id: rng.next_u64(),
+                    page_kind: rng.next_u64() as u16,
+                    page_flags: rng.next_u64() as u16,
+                };
+                let options = ExecutionOptionsWire {
+                    scan_batch_channel_capacity: rng.next_u64() as u32,
+                    scan_idle_poll_interval_us: rng.next_u64() as u32,
+                    runtime_filter_enabled: rng.below(2) == 0,
+                };
+                let encoded = encode_backend(BackendExecutionToWorker::StartExecution {
+                    session_epoch,
+                    plan,
+                    options,
+                    scans: ScanChannelSet::new(&channels).expect("valid scan set"),
+                });
+                let BackendExecutionToWorkerRef::StartExecution {
+                    session_epoch: decoded_epoch,
+                    plan: decoded_plan,
+                    options: decoded_options,
+                    scans,
+                } = decode_backend_execution_to_worker(&encoded).expect("decode")
+                else {
+                    panic!("expected start execution");
+                };
+                assert_eq!(decoded_epoch, session_epoch);
+                assert_eq!(decoded_plan, plan);
+                assert_eq!(decoded_options, options);
+                assert_eq!(scans.iter().collect::<Vec<_>>(), channels);
+            }
+            1 => {
+                let message = BackendExecutionToWorker::CancelExecution { session_epoch };
+                let encoded = encode_backend(message);
+                assert_eq!(
+                    decode_backend_execution_to_worker(&encoded).expect("decode"),
+                    BackendExecutionToWorkerRef::CancelExecution { session_epoch }
+                );
+            }
+            _ => {
+                let (code, detail) = random_failure(&mut rng);
+                let encoded = encode_backend(BackendExecutionToWorker::FailExecution {
+                    session_epoch,
+                    code,
+                    detail,
+                });
+                assert_eq!(
+                    decode_backend_execution_to_worker(&encoded).expect("decode"),
+                    BackendExecutionToWorkerRef::FailExecution {
+                        session_epoch,
+                        code,
+                        detail,
+                    }
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn worker_execution_round_trips_random_messages() {
+    let mut rng = FuzzRng(0x7066_5f72_7472_7032);
+    for _ in 0..ROUND_TRIP_CASES {
+        let session_epoch = rng.next_u64();
+        let message = if rng.below(2) == 0 {
+            WorkerExecutionToBackend::CompleteExecution { session_epoch }
+        } else {
+            let (code, detail) = random_failure(&mut rng);
+            WorkerExecutionToBackend::FailExecution {
+                session_epoch,
+                code,
+                detail,
+            }
+        };
+        let encoded = encode_worker_execution(message);
+        assert_eq!(
+            decode_worker_execution_to_backend(&encoded).expect("decode"),
+            message
+        );
+    }
+}
+
+#[test]
+fn worker_scan_round_trips_random_messages() {
+    let mut rng = FuzzRng(0x7066_5f72_7472_7033);
+    for _ in 0..ROUND_TRIP_CASES {
+        let session_epoch = rng.next_u64();
+        let scan_id = rng.next_u64();
+        if rng.below(2) == 0 {
+            let producers = random_producers(&mut rng);
+            let page_kind = rng.next_u64() as u16;
+            let page_flags = rng.next_u64() as u16;
+            let encoded = encode_worker_scan(WorkerScanToBackend::OpenScan {
+                session_epoch,
+                scan_id,
+                scan: ScanFlowDescriptor::new(page_kind, page_flags, &producers)
+                    .expect("valid producers"),
+            });
+            let decoded = decode_worker_scan_to_backend(&encoded).expect("decode");
+            let (decoded_epoch, decoded_scan_id, scan) = decode_open_scan(decoded);
+            assert_eq!(decoded_epoch, session_epoch);
+            assert_eq!(decoded_scan_id, scan_id);
+            assert_eq!(scan.page_kind, page_kind);
+            assert_eq!(scan.page_flags, page_flags);
+            assert_eq!(scan.producers().iter().collect::<Vec<_>>(), producers);
+        } else {
+            let encoded = encode_worker_scan(WorkerScanToBackend::CancelScan {
+                session_epoch,
+                scan_id,
+            });
+            assert_eq!(
+                decode_worker_scan_to_backend(&encoded).expect("decode"),
+                WorkerScanToBackendRef::CancelScan {
+                    session_epoch,
+                    scan_id,
+                }
+            );
+        }
+    }
+}
+
+#[test]
+fn backend_scan_round_trips_random_messages() {
+    let mut rng = FuzzRng(0x7066_5f72_7472_7034);
+    for _ in 0..ROUND_TRIP_CASES {
+        let session_epoch = rng.next_u64();
+        let scan_id = rng.next_u64();
+        let producer_id = rng.next_u64() as u16;
+        if rng.below(2) == 0 {
+            let encoded = encode_backend_scan(BackendScanToWorker::ScanFinished {
+                session_epoch,
+                scan_id,
+                producer_id,
+            });
+            assert_eq!(
+                decode_backend_scan_to_worker(&encoded).expect("decode"),
+                BackendScanToWorkerRef::ScanFinished {
+                    session_epoch,
+                    scan_id,
+                    producer_id,
+                }
+            );
+        } else {
+            let message = random_failure_message(&mut rng);
+            let encoded = encode_backend_scan(BackendScanToWorker::ScanFailed {
+                session_epoch,
+                scan_id,
+                producer_id,
+                message: &message,
+            });
+            assert_eq!(
+                decode_backend_scan_to_worker(&encoded).expect("decode"),
+                BackendScanToWorkerRef::ScanFailed {
+                    session_epoch,
+                    scan_id,
+                    producer_id,
+                    message: &message,
+                }
+            );
+        }
+    }
+}
@@ -445,7 +445,7 @@ fn decode_scan_inbound(bytes: &[u8]) -> Result<ScanInbound<'_>, WorkerRuntimeErr
             Ok(ScanInbound::Control(decode_backend_scan_to_worker(bytes)?))
         }
         Ok(other) => Err(WorkerRuntimeError::ProtocolViolation(format!(
-            "unexpected runtime family {other:?} on dedicated scan peer"
+            "unexpected runtime family {other} on dedicated scan peer"
         ))),
         Err(runtime_error)
             if matches!(